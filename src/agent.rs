@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::game_model::{Action, CroissantGame, CroissantGameConfig};
+
+pub trait Agent {
+    fn choose(&mut self, game: &CroissantGame) -> Action;
+}
+
+// Index into the Q-table's per-state action-value array.
+const ACTION_COOK: usize = 0;
+const ACTION_BUY_CHEESE: usize = 1;
+const ACTION_SELL_CHEESE: usize = 2;
+const ACTION_PUBLISH_RECIPE: usize = 3;
+const ACTION_PUBLISH_COOKBOOK: usize = 4;
+const ACTION_BUY_CROISSANTS: usize = 5;
+const NUM_ACTIONS: usize = 6;
+
+const MONEY_BUCKET_SIZE: i32 = 500;
+const PRICE_BUCKET_SIZE: i32 = 50;
+
+// (turn, bucketed money, mature cheese, aging cheese, recipes, cookbooks, bucketed croissant price)
+type StateKey = (i32, i32, i32, i32, i32, i32, i32);
+
+fn state_key(game: &CroissantGame) -> StateKey {
+    let (mature_cheeses, aging_cheeses) = game.count_cheeses();
+    (
+        game.turn,
+        game.money.raw() / MONEY_BUCKET_SIZE,
+        mature_cheeses,
+        aging_cheeses,
+        game.recipes,
+        game.cookbooks,
+        game.croissant_price.raw() / PRICE_BUCKET_SIZE,
+    )
+}
+
+fn action_for_index(index: usize, cheese_quantity: u32, croissant_quantity: u32) -> Action {
+    match index {
+        ACTION_COOK => Action::Cook,
+        ACTION_BUY_CHEESE => Action::BuyCheese(cheese_quantity),
+        ACTION_SELL_CHEESE => Action::SellCheese,
+        ACTION_PUBLISH_RECIPE => Action::PublishRecipe,
+        ACTION_PUBLISH_COOKBOOK => Action::PublishCookbook,
+        ACTION_BUY_CROISSANTS => Action::BuyCroissants(croissant_quantity),
+        _ => unreachable!(),
+    }
+}
+
+fn index_for_action(action: Action) -> usize {
+    match action {
+        Action::Cook => ACTION_COOK,
+        Action::BuyCheese(_) => ACTION_BUY_CHEESE,
+        Action::SellCheese => ACTION_SELL_CHEESE,
+        Action::PublishRecipe => ACTION_PUBLISH_RECIPE,
+        Action::PublishCookbook => ACTION_PUBLISH_COOKBOOK,
+        Action::BuyCroissants(_) => ACTION_BUY_CROISSANTS,
+    }
+}
+
+// Tabular Q-learning agent over a discretized state space.
+pub struct QLearningAgent {
+    q: HashMap<StateKey, [f64; NUM_ACTIONS]>,
+    rng: StdRng,
+    alpha: f64,
+    gamma: f64,
+    epsilon: f64,
+    cheese_quantity: u32,
+    croissant_quantity: u32,
+}
+
+impl QLearningAgent {
+    pub fn new(alpha: f64, gamma: f64, cheese_quantity: u32, croissant_quantity: u32) -> Self {
+        QLearningAgent {
+            q: HashMap::new(),
+            rng: StdRng::from_entropy(),
+            alpha,
+            gamma,
+            epsilon: 1.0,
+            cheese_quantity,
+            croissant_quantity,
+        }
+    }
+
+    fn action_values(&mut self, key: StateKey) -> &mut [f64; NUM_ACTIONS] {
+        self.q.entry(key).or_insert([0.0; NUM_ACTIONS])
+    }
+
+    fn best_index(&mut self, key: StateKey) -> usize {
+        let values = self.action_values(key);
+        let mut best_index = 0;
+        let mut best_value = values[0];
+        for (index, &value) in values.iter().enumerate().skip(1) {
+            if value > best_value {
+                best_index = index;
+                best_value = value;
+            }
+        }
+        best_index
+    }
+
+    fn choose_index(&mut self, key: StateKey) -> usize {
+        if self.rng.gen::<f64>() < self.epsilon {
+            self.rng.gen_range(0..NUM_ACTIONS)
+        } else {
+            self.best_index(key)
+        }
+    }
+
+    fn update(&mut self, key: StateKey, action_index: usize, reward: f64, next_key: StateKey) {
+        let alpha = self.alpha;
+        let gamma = self.gamma;
+        let best_next_value = self.action_values(next_key).iter().cloned().fold(f64::MIN, f64::max);
+        let values = self.action_values(key);
+        values[action_index] += alpha * (reward + gamma * best_next_value - values[action_index]);
+    }
+
+    fn decay_epsilon(&mut self, decay: f64, minimum: f64) {
+        self.epsilon = (self.epsilon * decay).max(minimum);
+    }
+
+    // Returns the greedy policy as (state, best action index) pairs, for inspection.
+    pub fn greedy_policy(&self) -> Vec<(StateKey, usize)> {
+        self.q.iter().map(|(&key, values)| {
+            // Ties go to the lowest index, matching `best_index`.
+            let best_index = (1..NUM_ACTIONS).fold(0, |best, index| if values[index] > values[best] { index } else { best });
+            (key, best_index)
+        }).collect()
+    }
+}
+
+impl Agent for QLearningAgent {
+    fn choose(&mut self, game: &CroissantGame) -> Action {
+        let index = self.choose_index(state_key(game));
+        action_for_index(index, self.cheese_quantity, self.croissant_quantity)
+    }
+}
+
+// Reward given for an action that `CroissantGame` rejected.
+const INVALID_ACTION_PENALTY: f64 = -1.0;
+// Falls back to `Action::Cook` after this many rejected attempts in a turn, as a safety valve.
+const MAX_ACTION_ATTEMPTS_PER_TURN: u32 = 20;
+
+fn expected_croissants(money_delta: i32, croissant_price: i32) -> f64 {
+    money_delta as f64 / croissant_price.max(1) as f64
+}
+
+// Rewards croissants gained directly, or money gained converted to croissant-equivalent — never both.
+fn reward_for_outcome(croissant_gain: i32, money_gain: i32, croissant_price: i32) -> f64 {
+    if croissant_gain != 0 {
+        croissant_gain as f64
+    } else {
+        expected_croissants(money_gain, croissant_price)
+    }
+}
+
+fn run_episode(config: Rc<CroissantGameConfig>, agent: &mut QLearningAgent) -> i32 {
+    let mut game = CroissantGame::new(config);
+    while !game.is_game_over() {
+        let key = state_key(&game);
+        let mut attempts = 0;
+        loop {
+            let money_before = game.money.raw();
+            let croissants_before = game.croissants;
+            let croissant_price = game.croissant_price.raw();
+            let action = if attempts < MAX_ACTION_ATTEMPTS_PER_TURN {
+                agent.choose(&game)
+            } else {
+                Action::Cook
+            };
+            let index = index_for_action(action);
+            match game.apply_action(action) {
+                Ok(()) => {
+                    let reward = reward_for_outcome(game.croissants - croissants_before, game.money.raw() - money_before, croissant_price);
+                    let next_key = state_key(&game);
+                    agent.update(key, index, reward, next_key);
+                    break;
+                },
+                Err(_invalid_action) => {
+                    agent.update(key, index, INVALID_ACTION_PENALTY, key);
+                    attempts += 1;
+                },
+            }
+        }
+    }
+    game.croissants
+}
+
+// Trains a fresh `QLearningAgent` over `episodes` games, decaying epsilon from 1.0 to `min_epsilon`.
+// Returns the trained agent and its average final `croissants` over the last 10% of episodes.
+pub fn train(config: Rc<CroissantGameConfig>, episodes: u32, min_epsilon: f64) -> (QLearningAgent, f64) {
+    let mut agent = QLearningAgent::new(0.1, 0.95, config.cheese_quantity_maximum, config.croissant_quantity_maximum);
+    let decay = (min_epsilon / 1.0_f64).powf(1.0 / episodes.max(1) as f64);
+
+    let scoring_window = (episodes / 10).max(1);
+    let mut recent_scores = Vec::new();
+    for episode in 0..episodes {
+        let score = run_episode(config.clone(), &mut agent);
+        if episode >= episodes.saturating_sub(scoring_window) {
+            recent_scores.push(score as f64);
+        }
+        agent.decay_epsilon(decay, min_epsilon);
+    }
+
+    let average_score = recent_scores.iter().sum::<f64>() / recent_scores.len() as f64;
+    (agent, average_score)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::game_model::Money;
+
+    fn base_config() -> CroissantGameConfig {
+        CroissantGameConfig {
+            turns: 10,
+            starting_money: Money::new(10_000),
+            cook_payoff: Money::new(100),
+            cheese_cost: Money::new(200),
+            cheese_quantity_maximum: 10,
+            cheese_mature_turns: 3,
+            cheese_payoff: Money::new(500),
+            recipe_cost: Money::new(1_000),
+            recipe_dividend: Money::new(50),
+            cookbook_cost: Money::new(2_000),
+            cookbook_dividend: Money::new(100),
+            croissant_starting_price: Money::new(500),
+            croissant_quantity_maximum: 20,
+            croissant_price_fall: Money::new(50),
+            croissant_price_rise: Money::new(20),
+            croissant_minimum_price: Money::new(100),
+            croissant_event_probability: 0.0,
+            croissant_event_min_price: Money::new(900),
+            croissant_event_max_price: Money::new(900),
+        }
+    }
+
+    #[test]
+    fn state_key_buckets_money_and_price_on_a_fresh_game() {
+        let config = Rc::new(base_config());
+        let game = CroissantGame::new_with_seed(config, 1);
+        // 10,000 cents / 500-cent bucket = 20; starting price 500 cents / 50-cent bucket = 10.
+        assert_eq!(state_key(&game), (1, 20, 0, 0, 0, 0, 10));
+    }
+
+    #[test]
+    fn choose_index_is_greedy_when_epsilon_is_zero() {
+        let mut agent = QLearningAgent::new(0.1, 0.95, 5, 5);
+        agent.epsilon = 0.0;
+        let key = (0, 0, 0, 0, 0, 0, 0);
+        agent.action_values(key)[ACTION_SELL_CHEESE] = 10.0;
+        for _ in 0..20 {
+            assert_eq!(agent.choose_index(key), ACTION_SELL_CHEESE);
+        }
+    }
+
+    #[test]
+    fn choose_index_explores_when_epsilon_is_one() {
+        let mut agent = QLearningAgent::new(0.1, 0.95, 5, 5);
+        agent.epsilon = 1.0;
+        let key = (0, 0, 0, 0, 0, 0, 0);
+        agent.action_values(key)[ACTION_SELL_CHEESE] = 10.0;
+        let seen: HashSet<usize> = (0..50).map(|_| agent.choose_index(key)).collect();
+        assert!(seen.len() > 1, "epsilon = 1.0 should explore instead of always picking the best action");
+    }
+
+    #[test]
+    fn reward_rewards_croissants_gained_over_money_spent_to_buy_them() {
+        assert_eq!(reward_for_outcome(3, -150, 50), 3.0);
+    }
+
+    #[test]
+    fn reward_rewards_money_as_croissant_equivalent_when_none_were_bought() {
+        assert_eq!(reward_for_outcome(0, 200, 50), 4.0);
+    }
+}
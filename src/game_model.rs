@@ -1,22 +1,30 @@
 use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
 use std::rc::Rc;
 
-use serde::Deserialize;
+use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
 pub enum InvalidActionErrorCause {
     InvalidAction,
     InvalidQuantity,
     ExtraneousQuantity,
     GameOver,
-    NotEnoughMoney(i32),
+    NotEnoughMoney(Money),
     CheeseMaxQuantityExceeded(u32),
     NoCheeseToSell,
     CroissantMaxQuantityExceeded(u32),
+    Overflow,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InvalidActionError {
     pub cause: InvalidActionErrorCause,
 }
@@ -32,6 +40,7 @@ impl InvalidActionError {
             InvalidActionErrorCause::CheeseMaxQuantityExceeded(max) => format!("Cannot buy that much cheese (max {}).", max),
             InvalidActionErrorCause::NoCheeseToSell => "You have no mature cheese to sell.".to_string(),
             InvalidActionErrorCause::CroissantMaxQuantityExceeded(max) => format!("Cannot buy that many croissants (max {}).", max),
+            InvalidActionErrorCause::Overflow => "That would overflow the game's accounting.".to_string(),
         }
     }
 }
@@ -45,50 +54,153 @@ impl fmt::Display for InvalidActionError {
 pub type ActionResult<T> = std::result::Result<T, InvalidActionError>;
 
 
-pub fn format_money(raw_money: i32) -> String {
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Cook,
+    BuyCheese(u32),
+    SellCheese,
+    PublishRecipe,
+    PublishCookbook,
+    BuyCroissants(u32),
+}
+
+
+fn overflow_error() -> InvalidActionError {
+    InvalidActionError { cause: InvalidActionErrorCause::Overflow }
+}
+
+// A whole number of cents. Arithmetic is checked and returns `ActionResult` instead of
+// wrapping or panicking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Money(i32);
+
+impl Money {
+    pub fn new(raw_cents: i32) -> Self {
+        Money(raw_cents)
+    }
+
+    pub fn raw(self) -> i32 {
+        self.0
+    }
+
+    pub fn add(self, other: Money) -> ActionResult<Money> {
+        self.0.checked_add(other.0).map(Money).ok_or_else(overflow_error)
+    }
+
+    pub fn sub(self, other: Money) -> ActionResult<Money> {
+        self.0.checked_sub(other.0).map(Money).ok_or_else(overflow_error)
+    }
+
+    pub fn mul(self, factor: i32) -> ActionResult<Money> {
+        self.0.checked_mul(factor).map(Money).ok_or_else(overflow_error)
+    }
+
+    // Like `sub`, but additionally enforces that a balance never drops below zero. Use this
+    // for spending out of `money`, where going negative would mean a bug let a purchase
+    // through that the player couldn't afford.
+    pub fn sub_nonnegative(self, other: Money) -> ActionResult<Money> {
+        let result = self.sub(other)?;
+        if result.0 < 0 {
+            return Err(overflow_error());
+        }
+        Ok(result)
+    }
+}
+
+
+pub fn format_money(money: Money) -> String {
+    let raw_money = money.raw();
     format!("${}.{:02}", raw_money / 100, raw_money % 100)
 }
 
 
-#[derive(Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct CroissantGameConfig {
     pub turns: i32,
-    pub starting_money: i32,
-    pub cook_payoff: i32,
-    pub cheese_cost: i32,
+    pub starting_money: Money,
+    pub cook_payoff: Money,
+    pub cheese_cost: Money,
     pub cheese_quantity_maximum: u32,
     pub cheese_mature_turns: i32,
-    pub cheese_payoff: i32,
-    pub recipe_cost: i32,
-    pub recipe_dividend: i32,
-    pub cookbook_cost: i32,
-    pub cookbook_dividend: i32,
-    pub croissant_starting_price: i32,
+    pub cheese_payoff: Money,
+    pub recipe_cost: Money,
+    pub recipe_dividend: Money,
+    pub cookbook_cost: Money,
+    pub cookbook_dividend: Money,
+    pub croissant_starting_price: Money,
     pub croissant_quantity_maximum: u32,
-    pub croissant_price_fall: i32,
-    pub croissant_price_rise: i32,
-    pub croissant_minimum_price: i32,
+    pub croissant_price_fall: Money,
+    pub croissant_price_rise: Money,
+    pub croissant_minimum_price: Money,
+    pub croissant_event_probability: f64,
+    pub croissant_event_min_price: Money,
+    pub croissant_event_max_price: Money,
+}
+
+
+// A point-in-time view of a `CroissantGame`, for the `--json` headless protocol. Unlike
+// `CroissantGame` itself, this is plain data: safe to serialize and send to an external bot.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameStateSnapshot {
+    pub turn: i32,
+    pub money: Money,
+    pub mature_cheeses: i32,
+    pub aging_cheeses: i32,
+    pub recipes: i32,
+    pub cookbooks: i32,
+    pub croissant_price: Money,
+    pub croissants: i32,
+    pub legal_actions: Vec<&'static str>,
+    pub game_over: bool,
 }
 
 
+// The on-disk form of a suspended `CroissantGame`.
+#[derive(Serialize, Deserialize)]
+struct SavedGame {
+    config: CroissantGameConfig,
+    turn: i32,
+    money: Money,
+    cheeses: Vec<i32>,
+    recipes: i32,
+    cookbooks: i32,
+    croissant_price: Money,
+    croissants: i32,
+    seed: u64,
+    action_log: Vec<Action>,
+}
+
 pub struct CroissantGame {
     config: Rc<CroissantGameConfig>,
 
     pub turn: i32,
-    pub money: i32,
+    pub money: Money,
     pub cheeses: Vec<i32>,
     pub recipes: i32,
     pub cookbooks: i32,
-    pub croissant_price: i32,
+    pub croissant_price: Money,
     pub croissants: i32,
+
+    // The seed the game's RNG was created with, and the ordered log of actions applied to it
+    // so far. Together these let `replay` reconstruct the exact same sequence of states.
+    pub seed: u64,
+    pub action_log: Vec<Action>,
+
+    rng: StdRng,
 }
 
 impl CroissantGame {
     pub fn new(config: Rc<CroissantGameConfig>) -> Self {
+        Self::new_with_seed(config, rand::random())
+    }
+
+    pub fn new_with_seed(config: Rc<CroissantGameConfig>, seed: u64) -> Self {
         let starting_money = config.starting_money;
         let croissant_starting_price = config.croissant_starting_price;
         CroissantGame {
-            config: config,
+            config,
             turn: 1,
             money: starting_money,
             cheeses: vec![],
@@ -96,7 +208,63 @@ impl CroissantGame {
             cookbooks: 0,
             croissant_price: croissant_starting_price,
             croissants: 0,
+            seed,
+            action_log: vec![],
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    // Re-executes `actions` against a fresh game seeded the same way a saved game was, so the
+    // two can be compared to confirm the log reproduces the original run exactly.
+    pub fn replay(config: Rc<CroissantGameConfig>, seed: u64, actions: &[Action]) -> ActionResult<CroissantGame> {
+        let mut game = CroissantGame::new_with_seed(config, seed);
+        for &action in actions {
+            game.apply_action(action)?;
+        }
+        Ok(game)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let saved = SavedGame {
+            config: (*self.config).clone(),
+            turn: self.turn,
+            money: self.money,
+            cheeses: self.cheeses.clone(),
+            recipes: self.recipes,
+            cookbooks: self.cookbooks,
+            croissant_price: self.croissant_price,
+            croissants: self.croissants,
+            seed: self.seed,
+            action_log: self.action_log.clone(),
+        };
+        let json = serde_json::to_string_pretty(&saved)?;
+        fs::write(path, json)
+    }
+
+    // Loads a saved game and replays its action log from scratch to reconstruct it, then
+    // verifies the replay landed on the same state that was saved. A save file that doesn't
+    // reproduce its own recorded state (e.g. hand-edited, or saved by a different game version)
+    // is rejected rather than silently trusted.
+    pub fn load(path: &Path) -> io::Result<CroissantGame> {
+        let json = fs::read_to_string(path)?;
+        let saved: SavedGame = serde_json::from_str(&json)?;
+        let config = Rc::new(saved.config);
+
+        let replayed = CroissantGame::replay(config, saved.seed, &saved.action_log)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.describe()))?;
+
+        if replayed.turn != saved.turn
+            || replayed.money != saved.money
+            || replayed.cheeses != saved.cheeses
+            || replayed.recipes != saved.recipes
+            || replayed.cookbooks != saved.cookbooks
+            || replayed.croissant_price != saved.croissant_price
+            || replayed.croissants != saved.croissants
+        {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "action log did not reproduce the saved game state"));
         }
+
+        Ok(replayed)
     }
 
     pub fn is_game_over(&self) -> bool {
@@ -117,22 +285,76 @@ impl CroissantGame {
         (mature, non_mature)
     }
 
-    fn end_turn(&mut self) {
+    pub fn snapshot(&self) -> GameStateSnapshot {
+        let (mature_cheeses, aging_cheeses) = self.count_cheeses();
+        GameStateSnapshot {
+            turn: self.turn,
+            money: self.money,
+            mature_cheeses,
+            aging_cheeses,
+            recipes: self.recipes,
+            cookbooks: self.cookbooks,
+            croissant_price: self.croissant_price,
+            croissants: self.croissants,
+            legal_actions: self.legal_actions(mature_cheeses),
+            game_over: self.is_game_over(),
+        }
+    }
+
+    fn legal_actions(&self, mature_cheeses: i32) -> Vec<&'static str> {
+        if self.is_game_over() {
+            return vec![];
+        }
+        let mut actions = vec!["cook"];
+        if self.money >= self.config.cheese_cost {
+            actions.push("buy_cheese");
+        }
+        if mature_cheeses > 0 {
+            actions.push("sell_cheese");
+        }
+        if self.money >= self.config.recipe_cost {
+            actions.push("publish_recipe");
+        }
+        if self.money >= self.config.cookbook_cost {
+            actions.push("publish_cookbook");
+        }
+        if self.money >= self.croissant_price {
+            actions.push("buy_croissants");
+        }
+        actions
+    }
+
+    // `croissants_bought` is the quantity purchased this turn via `execute_buy_croissants`,
+    // or 0 if the turn's action didn't touch the croissant market.
+    fn end_turn(&mut self, croissants_bought: u32) -> ActionResult<()> {
         self.turn += 1;
         for i in 0..self.cheeses.len() {
             self.cheeses[i] += 1;
         }
-        self.money += self.config.recipe_dividend * self.recipes;
-        self.money += self.config.cookbook_dividend * self.cookbooks;
+        self.money = self.money.add(self.config.recipe_dividend.mul(self.recipes)?)?;
+        self.money = self.money.add(self.config.cookbook_dividend.mul(self.cookbooks)?)?;
+
+        let fallen_price = self.croissant_price.sub(self.config.croissant_price_fall)?;
+        self.croissant_price = fallen_price.max(self.config.croissant_minimum_price);
+        if croissants_bought > 0 {
+            let price_push = self.config.croissant_price_rise.mul(croissants_bought as i32)?;
+            self.croissant_price = self.croissant_price.add(price_push)?;
+        }
+
+        if self.rng.gen::<f64>() < self.config.croissant_event_probability {
+            let shocked_price = Money::new(self.rng.gen_range(self.config.croissant_event_min_price.raw()..=self.config.croissant_event_max_price.raw()));
+            self.croissant_price = shocked_price.max(self.config.croissant_minimum_price);
+        }
+
+        Ok(())
     }
 
     pub fn execute_cook(&mut self) -> ActionResult<()> {
         if self.is_game_over() {
             return Err(InvalidActionError { cause: InvalidActionErrorCause::GameOver });
         }
-        self.money += self.config.cook_payoff;
-        self.end_turn();
-        Ok(())
+        self.money = self.money.add(self.config.cook_payoff)?;
+        self.end_turn(0)
     }
 
     pub fn execute_buy_cheese(&mut self, quantity: u32) -> ActionResult<()> {
@@ -145,15 +367,14 @@ impl CroissantGame {
         if quantity > self.config.cheese_quantity_maximum {
             return Err(InvalidActionError { cause: InvalidActionErrorCause::CheeseMaxQuantityExceeded(self.config.cheese_quantity_maximum) });
         }
-        let total_cost = self.config.cheese_cost * quantity as i32;
+        let total_cost = self.config.cheese_cost.mul(quantity as i32)?;
         if total_cost > self.money {
             return Err(InvalidActionError { cause: InvalidActionErrorCause::NotEnoughMoney(total_cost) });
         }
-        self.money -= total_cost;
+        self.money = self.money.sub_nonnegative(total_cost)?;
         let mut new_cheeses = vec![ 0 ; quantity as usize ];
         self.cheeses.append(&mut new_cheeses);
-        self.end_turn();
-        Ok(())
+        self.end_turn(0)
     }
 
     pub fn execute_sell_cheese(&mut self) -> ActionResult<()> {
@@ -164,11 +385,10 @@ impl CroissantGame {
         if mature_cheeses == 0 {
             return Err(InvalidActionError { cause: InvalidActionErrorCause::NoCheeseToSell });
         }
-        let total_gain = mature_cheeses * self.config.cheese_payoff;
-        self.money += total_gain;
-        self.cheeses = self.cheeses.iter().filter(|&&age| age < self.config.cheese_mature_turns).cloned().collect();
-        self.end_turn();
-        Ok(())
+        let total_gain = self.config.cheese_payoff.mul(mature_cheeses)?;
+        self.money = self.money.add(total_gain)?;
+        self.cheeses.retain(|&age| age < self.config.cheese_mature_turns);
+        self.end_turn(0)
     }
 
     pub fn execute_publish_recipe(&mut self) -> ActionResult<()> {
@@ -178,10 +398,9 @@ impl CroissantGame {
         if self.config.recipe_cost > self.money {
             return Err(InvalidActionError { cause: InvalidActionErrorCause::NotEnoughMoney(self.config.recipe_cost) });
         }
-        self.money -= self.config.recipe_cost;
+        self.money = self.money.sub_nonnegative(self.config.recipe_cost)?;
         self.recipes += 1;
-        self.end_turn();
-        Ok(())
+        self.end_turn(0)
     }
 
     pub fn execute_publish_cookbook(&mut self) -> ActionResult<()> {
@@ -191,10 +410,9 @@ impl CroissantGame {
         if self.config.cookbook_cost > self.money {
             return Err(InvalidActionError { cause: InvalidActionErrorCause::NotEnoughMoney(self.config.cookbook_cost) });
         }
-        self.money -= self.config.cookbook_cost;
+        self.money = self.money.sub_nonnegative(self.config.cookbook_cost)?;
         self.cookbooks += 1;
-        self.end_turn();
-        Ok(())
+        self.end_turn(0)
     }
 
     pub fn execute_buy_croissants(&mut self, quantity: u32) -> ActionResult<()> {
@@ -207,13 +425,207 @@ impl CroissantGame {
         if quantity > self.config.croissant_quantity_maximum {
             return Err(InvalidActionError { cause: InvalidActionErrorCause::CroissantMaxQuantityExceeded(self.config.croissant_quantity_maximum) });
         }
-        let total_cost = self.croissant_price * quantity as i32;
+        let total_cost = self.croissant_price.mul(quantity as i32)?;
         if total_cost > self.money {
             return Err(InvalidActionError { cause: InvalidActionErrorCause::NotEnoughMoney(total_cost) });
         }
-        self.money -= total_cost;
+        self.money = self.money.sub_nonnegative(total_cost)?;
         self.croissants += quantity as i32;
-        self.end_turn();
-        Ok(())
+        self.end_turn(quantity)
+    }
+
+    pub fn apply_action(&mut self, action: Action) -> ActionResult<()> {
+        let result = match action {
+            Action::Cook => self.execute_cook(),
+            Action::BuyCheese(quantity) => self.execute_buy_cheese(quantity),
+            Action::SellCheese => self.execute_sell_cheese(),
+            Action::PublishRecipe => self.execute_publish_recipe(),
+            Action::PublishCookbook => self.execute_publish_cookbook(),
+            Action::BuyCroissants(quantity) => self.execute_buy_croissants(quantity),
+        };
+        if result.is_ok() {
+            self.action_log.push(action);
+        }
+        result
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_config() -> CroissantGameConfig {
+        CroissantGameConfig {
+            turns: 10,
+            starting_money: Money::new(10_000),
+            cook_payoff: Money::new(100),
+            cheese_cost: Money::new(200),
+            cheese_quantity_maximum: 10,
+            cheese_mature_turns: 3,
+            cheese_payoff: Money::new(500),
+            recipe_cost: Money::new(1_000),
+            recipe_dividend: Money::new(50),
+            cookbook_cost: Money::new(2_000),
+            cookbook_dividend: Money::new(100),
+            croissant_starting_price: Money::new(500),
+            croissant_quantity_maximum: 20,
+            croissant_price_fall: Money::new(50),
+            croissant_price_rise: Money::new(20),
+            croissant_minimum_price: Money::new(100),
+            croissant_event_probability: 0.0,
+            croissant_event_min_price: Money::new(900),
+            croissant_event_max_price: Money::new(900),
+        }
+    }
+
+    #[test]
+    fn price_drifts_down_each_turn() {
+        let config = Rc::new(base_config());
+        let mut game = CroissantGame::new_with_seed(config, 1);
+        game.execute_cook().unwrap();
+        assert_eq!(game.croissant_price, Money::new(450));
+    }
+
+    #[test]
+    fn price_does_not_fall_below_minimum() {
+        let config = Rc::new(CroissantGameConfig { croissant_price_fall: Money::new(10_000), ..base_config() });
+        let mut game = CroissantGame::new_with_seed(config.clone(), 1);
+        game.execute_cook().unwrap();
+        assert_eq!(game.croissant_price, config.croissant_minimum_price);
+    }
+
+    #[test]
+    fn buying_croissants_pushes_the_price_up() {
+        let config = Rc::new(base_config());
+        let mut game = CroissantGame::new_with_seed(config, 1);
+        let price_before = game.croissant_price;
+        game.execute_buy_croissants(3).unwrap();
+        // The price falls by `croissant_price_fall`, then rises by `croissant_price_rise * quantity`.
+        let expected = price_before.sub(Money::new(50)).unwrap().add(Money::new(20 * 3)).unwrap();
+        assert_eq!(game.croissant_price, expected);
+    }
+
+    #[test]
+    fn random_market_events_land_within_the_configured_band() {
+        let config = Rc::new(CroissantGameConfig {
+            croissant_event_probability: 1.0,
+            croissant_event_min_price: Money::new(999),
+            croissant_event_max_price: Money::new(999),
+            ..base_config()
+        });
+        let mut game = CroissantGame::new_with_seed(config, 1);
+        game.execute_cook().unwrap();
+        assert_eq!(game.croissant_price, Money::new(999));
+    }
+
+    #[test]
+    fn money_add_overflows_at_i32_max() {
+        assert!(matches!(
+            Money::new(i32::MAX).add(Money::new(1)),
+            Err(InvalidActionError { cause: InvalidActionErrorCause::Overflow })
+        ));
+    }
+
+    #[test]
+    fn money_sub_overflows_at_i32_min() {
+        assert!(matches!(
+            Money::new(i32::MIN).sub(Money::new(1)),
+            Err(InvalidActionError { cause: InvalidActionErrorCause::Overflow })
+        ));
+    }
+
+    #[test]
+    fn money_mul_overflows_past_i32_max() {
+        assert!(matches!(
+            Money::new(i32::MAX).mul(2),
+            Err(InvalidActionError { cause: InvalidActionErrorCause::Overflow })
+        ));
+    }
+
+    #[test]
+    fn money_sub_nonnegative_allows_an_exact_balance() {
+        assert_eq!(Money::new(500).sub_nonnegative(Money::new(500)).unwrap(), Money::new(0));
+    }
+
+    #[test]
+    fn money_sub_nonnegative_rejects_going_negative() {
+        assert!(matches!(
+            Money::new(500).sub_nonnegative(Money::new(501)),
+            Err(InvalidActionError { cause: InvalidActionErrorCause::Overflow })
+        ));
+    }
+
+    #[test]
+    fn save_then_load_round_trips_the_full_game_state() {
+        let config = Rc::new(base_config());
+        let mut game = CroissantGame::new_with_seed(config, 1);
+        game.apply_action(Action::Cook).unwrap();
+        game.apply_action(Action::BuyCroissants(3)).unwrap();
+
+        let path = std::env::temp_dir().join(format!("croissant_bench_test_{}.json", std::process::id()));
+        game.save(&path).unwrap();
+        let loaded = CroissantGame::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.turn, game.turn);
+        assert_eq!(loaded.money, game.money);
+        assert_eq!(loaded.cheeses, game.cheeses);
+        assert_eq!(loaded.recipes, game.recipes);
+        assert_eq!(loaded.cookbooks, game.cookbooks);
+        assert_eq!(loaded.croissant_price, game.croissant_price);
+        assert_eq!(loaded.croissants, game.croissants);
+        assert_eq!(loaded.seed, game.seed);
+        assert_eq!(loaded.action_log, game.action_log);
+    }
+
+    #[test]
+    fn replay_reproduces_a_game_driven_by_random_market_events() {
+        let config = Rc::new(CroissantGameConfig {
+            croissant_event_probability: 1.0,
+            croissant_event_min_price: Money::new(100),
+            croissant_event_max_price: Money::new(2_000),
+            ..base_config()
+        });
+        let seed = 7;
+        let actions = [Action::Cook, Action::BuyCroissants(1), Action::Cook, Action::PublishRecipe];
+
+        let mut original = CroissantGame::new_with_seed(config.clone(), seed);
+        for &action in &actions {
+            original.apply_action(action).unwrap();
+        }
+
+        // Every turn above rolled a random market event, so this only matches if `replay` drove
+        // the RNG through the exact same sequence of draws as the original run.
+        let replayed = CroissantGame::replay(config, seed, &actions).unwrap();
+        assert_eq!(replayed.croissant_price, original.croissant_price);
+        assert_eq!(replayed.money, original.money);
+        assert_eq!(replayed.croissants, original.croissants);
+    }
+
+    #[test]
+    fn action_json_shape_uses_snake_case_matching_legal_actions() {
+        assert_eq!(serde_json::to_string(&Action::Cook).unwrap(), "\"cook\"");
+        assert_eq!(serde_json::to_string(&Action::BuyCheese(3)).unwrap(), "{\"buy_cheese\":3}");
+
+        let parsed: Action = serde_json::from_str("{\"buy_croissants\":2}").unwrap();
+        assert_eq!(parsed, Action::BuyCroissants(2));
+    }
+
+    #[test]
+    fn invalid_action_error_json_does_not_double_nest_the_cause_field() {
+        let error = InvalidActionError { cause: InvalidActionErrorCause::NotEnoughMoney(Money::new(500)) };
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["cause"]["kind"], "not_enough_money");
+        assert_eq!(json["cause"]["detail"].as_i64(), Some(500));
+        assert!(json["cause"]["cause"].is_null());
+    }
+
+    #[test]
+    fn snapshot_legal_actions_use_the_same_casing_the_wire_format_accepts() {
+        let config = Rc::new(base_config());
+        let game = CroissantGame::new_with_seed(config, 1);
+        let json = serde_json::to_value(game.snapshot()).unwrap();
+        assert_eq!(json["legal_actions"], serde_json::json!(["cook", "buy_cheese", "publish_recipe", "publish_cookbook", "buy_croissants"]));
     }
 }
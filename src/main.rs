@@ -1,13 +1,19 @@
+mod agent;
 mod game_model;
 
 use std::io;
 use std::io::Write;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::LazyLock;
 
 use regex::Regex;
 
-use game_model::{CroissantGame, CroissantGameConfig, format_money};
+use game_model::{Action, CroissantGame, CroissantGameConfig, InvalidActionError, InvalidActionErrorCause, format_money};
+
+
+const BENCH_EPISODES: u32 = 20_000;
+const BENCH_MIN_EPSILON: f64 = 0.05;
 
 
 static ACTION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d+)(\s*\d*)").unwrap());
@@ -46,23 +52,88 @@ fn process_action(game: &mut CroissantGame, action: &str) -> game_model::ActionR
             return Err(game_model::InvalidActionError { cause: game_model::InvalidActionErrorCause::InvalidAction });
         },
     };
-    // at this point everything should be validated, so execute actions
-    match &captures[1] {
-        "1" => game.execute_cook(),
-        "2" => game.execute_buy_cheese(maybe_quantity.unwrap()),
-        "3" => game.execute_sell_cheese(),
-        "4" => game.execute_publish_recipe(),
-        "5" => game.execute_publish_cookbook(),
-        "6" => game.execute_buy_croissants(maybe_quantity.unwrap()),
+    // at this point everything should be validated, so build and apply the action
+    let action = match &captures[1] {
+        "1" => Action::Cook,
+        "2" => Action::BuyCheese(maybe_quantity.unwrap()),
+        "3" => Action::SellCheese,
+        "4" => Action::PublishRecipe,
+        "5" => Action::PublishCookbook,
+        "6" => Action::BuyCroissants(maybe_quantity.unwrap()),
         _ => unreachable!(),
+    };
+    game.apply_action(action)
+}
+
+// Trains a Q-learning agent against a fresh `CroissantGame` for `BENCH_EPISODES` episodes and
+// prints its converged average score and learned greedy policy, instead of running the
+// interactive CLI. Lets the game config be tuned and benchmarked without a human at the wheel.
+fn run_bench(game_config: Rc<CroissantGameConfig>) {
+    let (trained_agent, average_score) = agent::train(game_config, BENCH_EPISODES, BENCH_MIN_EPSILON);
+    println!("Trained over {} episodes.", BENCH_EPISODES);
+    println!("Average final croissants over the last {}% of episodes: {:.2}", 10, average_score);
+    println!("Learned greedy policy ({} states visited):", trained_agent.greedy_policy().len());
+    for (state, best_action_index) in trained_agent.greedy_policy() {
+        println!("{:?} -> action {}", state, best_action_index);
     }
 }
 
+// Runs the game loop headlessly: each turn prints the game state as a single JSON line and
+// reads back a single JSON `Action` line, instead of the human menu. Lets external bots and
+// test harnesses drive the same engine as the interactive CLI.
+fn run_json(game_config: Rc<CroissantGameConfig>) {
+    let mut game = CroissantGame::new(game_config);
+
+    while !game.is_game_over() {
+        println!("{}", serde_json::to_string(&game.snapshot()).unwrap());
+        io::stdout().flush().expect("failed flush");
+
+        let mut line = String::new();
+        // A 0-byte read means the bot closed its end of the pipe (EOF); stop instead of
+        // spinning on an empty line forever.
+        let bytes_read = io::stdin().read_line(&mut line).unwrap_or(0);
+        if bytes_read == 0 {
+            break;
+        }
+
+        let result = serde_json::from_str::<Action>(line.trim())
+            .map_err(|_error| InvalidActionError { cause: InvalidActionErrorCause::InvalidAction })
+            .and_then(|action| game.apply_action(action));
+        if let Err(e) = result {
+            println!("{}", serde_json::to_string(&e).unwrap());
+        }
+    }
+
+    println!("{}", serde_json::to_string(&game.snapshot()).unwrap());
+}
+
+// Returns the path passed to `--save <path>`, if any: a save file to resume from on startup
+// and to write progress to on request, so a game can be suspended and continued later.
+fn save_path_arg() -> Option<PathBuf> {
+    let args: Vec<String> = std::env::args().collect();
+    let index = args.iter().position(|arg| arg == "--save")?;
+    Some(PathBuf::from(args.get(index + 1).expect("--save requires a path")))
+}
+
 fn main() {
     let game_config_owned: CroissantGameConfig = toml::from_str(include_str!("game_config.toml")).unwrap();
     let game_config = Rc::new(game_config_owned);
 
-    let mut game = CroissantGame::new(game_config.clone());
+    if std::env::args().any(|arg| arg == "--bench") {
+        run_bench(game_config);
+        return;
+    }
+
+    if std::env::args().any(|arg| arg == "--json") {
+        run_json(game_config);
+        return;
+    }
+
+    let save_path = save_path_arg();
+    let mut game = match &save_path {
+        Some(path) if path.exists() => CroissantGame::load(path).expect("failed to load saved game"),
+        _ => CroissantGame::new(game_config.clone()),
+    };
 
     while !game.is_game_over() {
         let (mature_cheeses, non_mature_cheeses) = game.count_cheeses();
@@ -72,7 +143,7 @@ fn main() {
         println!("- {} recipes", game.recipes);
         println!("- {} cookbooks", game.cookbooks);
         println!("- {} croissants", game.croissants);
-        println!("");
+        println!();
 
         println!("The market price of croissants is {}.", format_money(game.croissant_price));
 
@@ -82,9 +153,20 @@ fn main() {
         println!("4. Publish 1 recipe");
         println!("5. Publish 1 cookbook");
         println!("6. Buy croissants [quantity]");
-        println!("");
+        if save_path.is_some() {
+            println!("7. Save and quit");
+        }
+        println!();
 
         let action = prompt_user().to_lowercase();
+        if action == "7" {
+            if let Some(path) = &save_path {
+                game.save(path).expect("failed to save game");
+                println!("\nGame saved. See you next time!\n");
+                return;
+            }
+        }
+
         let result = process_action(&mut game, &action);
         match result {
             Ok(()) => println!("\n================================\n"),
@@ -92,5 +174,8 @@ fn main() {
         };
     }
 
+    if let Some(path) = &save_path {
+        game.save(path).expect("failed to save game");
+    }
     println!("Game over! You earned {} croissants.\n", game.croissants);
 }